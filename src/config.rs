@@ -0,0 +1,112 @@
+//! Persisted window/DPI settings, analogous to how terminal emulators keep their window
+//! padding and scale factor in a config file instead of recomputing defaults every launch.
+//!
+//! Wiring this module in requires, alongside `mod config;` in the crate root:
+//! ```toml
+//! [dependencies]
+//! serde = { version = "1", features = ["derive"] }
+//! toml = "0.5"
+//! directories = "4"
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const DEFAULT_WIDTH: f64 = 1024.0;
+const DEFAULT_HEIGHT: f64 = 768.0;
+
+// Bounds for a persisted `dpi_override`. A hand-edited or corrupted config can contain a
+// zero, negative, or absurd factor; `HiDpiMode::Locked` passes it straight through to the
+// renderer with no validation of its own, so a bad value would otherwise produce a
+// degenerate or unusable scale.
+const DPI_OVERRIDE_MIN: f64 = 0.1;
+const DPI_OVERRIDE_MAX: f64 = 8.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowConfig {
+    pub width: f64,
+    pub height: f64,
+    /// Logical (DPI-independent) top-left position, so it's meaningful across sessions
+    /// where the window lands on a monitor with a different scale factor.
+    pub position: Option<(f64, f64)>,
+    /// `HiDpiMode::Locked` factor the user has pinned, if any.
+    pub dpi_override: Option<f64>,
+    /// Extra logical padding added to `(width, height)` when building the window.
+    pub padding: (f32, f32),
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        WindowConfig {
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            position: None,
+            dpi_override: None,
+            padding: (0.0, 0.0),
+        }
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    let dirs = directories::ProjectDirs::from("com", "sharlock93", "search")?;
+    Some(dirs.config_dir().join("window.toml"))
+}
+
+/// Reads the window config, falling back to defaults if it doesn't exist or fails to parse.
+pub fn load() -> WindowConfig {
+    let path = match config_path() {
+        Some(path) => path,
+        None => return WindowConfig::default(),
+    };
+
+    let mut config: WindowConfig = match fs::read_to_string(&path) {
+        Ok(contents) => toml::from_str(&contents).unwrap_or_else(|e| {
+            eprintln!("Failed to parse window config at {}: {}", path.display(), e);
+            WindowConfig::default()
+        }),
+        Err(_) => WindowConfig::default(),
+    };
+
+    // `dpi_override` feeds straight into `HiDpiMode::Locked`, which doesn't validate it
+    // itself, so a hand-edited or corrupted `0.0`/negative/absurd factor would otherwise
+    // produce a degenerate or unusable scale.
+    if let Some(factor) = config.dpi_override {
+        if !(DPI_OVERRIDE_MIN..=DPI_OVERRIDE_MAX).contains(&factor) {
+            eprintln!(
+                "Ignoring out-of-range dpi_override {} in {} (expected {}..={})",
+                factor,
+                path.display(),
+                DPI_OVERRIDE_MIN,
+                DPI_OVERRIDE_MAX
+            );
+            config.dpi_override = None;
+        }
+    }
+
+    config
+}
+
+/// Writes the window config, creating the platform config directory if necessary.
+pub fn save(config: &WindowConfig) {
+    let path = match config_path() {
+        Some(path) => path,
+        None => return,
+    };
+
+    if let Some(parent) = path.parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            eprintln!("Failed to create config dir {}: {}", parent.display(), e);
+            return;
+        }
+    }
+
+    match toml::to_string_pretty(config) {
+        Ok(contents) => {
+            if let Err(e) = fs::write(&path, contents) {
+                eprintln!("Failed to write window config to {}: {}", path.display(), e);
+            }
+        }
+        Err(e) => eprintln!("Failed to serialize window config: {}", e),
+    }
+}
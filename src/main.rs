@@ -0,0 +1,11 @@
+mod app;
+mod clipboard;
+mod config;
+mod stb_image;
+mod support;
+
+fn main() {
+    let system = support::init("search");
+    let app = app::App::new();
+    system.main_loop(app);
+}
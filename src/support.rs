@@ -1,5 +1,5 @@
 use glium::glutin;
-use glium::glutin::event::{Event, WindowEvent};
+use glium::glutin::event::{ElementState, Event, MouseScrollDelta, VirtualKeyCode, WindowEvent};
 use glium::glutin::event_loop::{ControlFlow, EventLoop};
 use glium::glutin::window::{Icon, WindowBuilder};
 use glium::{Display, Surface};
@@ -10,7 +10,12 @@ use imgui_winit_support::{HiDpiMode, WinitPlatform};
 use std::path::Path;
 use std::time::Instant;
 
-use crate::{app::App, clipboard};
+use crate::{app::App, clipboard, config};
+
+// Bounds for `System::font_size_offset` so Ctrl+=/Ctrl+scroll can't rasterize a
+// degenerate (zero/negative) or absurdly large atlas.
+const FONT_SIZE_OFFSET_MIN: f32 = -6.0;
+const FONT_SIZE_OFFSET_MAX: f32 = 40.0;
 
 pub struct System {
     pub event_loop: EventLoop<()>,
@@ -18,6 +23,166 @@ pub struct System {
     pub imgui: Context,
     pub platform: WinitPlatform,
     pub renderer: Renderer,
+    // Logical (unscaled) pixel sizes for the fonts loaded in `build_fonts`, kept around so
+    // the atlas can be re-rasterized whenever the hidpi factor changes.
+    base_font_sizes: [f32; 2],
+    // Style as computed before `scale_all_sizes` was applied, so rescaling never compounds.
+    base_style: imgui::Style,
+    hidpi_factor: f32,
+    // User zoom applied on top of `base_font_sizes`, adjusted via Ctrl+=/Ctrl+-/Ctrl+0.
+    font_size_offset: f32,
+    // Extra logical padding applied to the configured window size; persisted as-is.
+    window_padding: (f32, f32),
+    // The config-file DPI override in effect, if any. Deliberately *not* set from
+    // `IMGUI_EXAMPLE_FORCE_DPI_FACTOR` (see `init`) so a debug-only env var never gets
+    // written back into the config on exit.
+    dpi_override: Option<f64>,
+    // Whether `HiDpiMode::Locked` is active for this run, from either `dpi_override` or the
+    // env var. Used to skip atlas rebuilds on `ScaleFactorChanged` while locked.
+    dpi_locked: bool,
+}
+
+fn build_fonts(imgui: &mut Context, base_font_sizes: [f32; 2], hidpi_factor: f32) {
+    imgui.fonts().add_font(&[
+        FontSource::TtfData {
+            data: include_bytes!("../resources/Lucon.ttf"),
+            size_pixels: base_font_sizes[0] * hidpi_factor,
+            config: Some(FontConfig {
+                // As imgui-glium-renderer isn't gamma-correct with it's font rendering,
+                // we apply an arbitrary multiplier to make the font a bit "heavier".
+                // With default imgui-glow-renderer this is unnecessary.
+                rasterizer_multiply: 1.2,
+                // Oversampling font helps improve text rendering at expense of larger
+                // font atlas texture.
+                oversample_h: 4,
+                oversample_v: 4,
+                ..FontConfig::default()
+            }),
+        },
+        FontSource::TtfData {
+            data: include_bytes!("../resources/mplus-1p-regular.ttf"),
+            size_pixels: base_font_sizes[1] * hidpi_factor,
+            config: Some(FontConfig {
+                // Oversampling font helps improve text rendering at expense of larger
+                // font atlas texture.
+                oversample_h: 4,
+                oversample_v: 4,
+                // Range of glyphs to rasterize
+                glyph_ranges: FontGlyphRanges::japanese(),
+                ..FontConfig::default()
+            }),
+        },
+        // Wide-coverage fallback, so any glyph Lucon/mplus don't have (Cyrillic, CJK
+        // extensions, Arabic, symbols, ...) still renders instead of showing a tofu box.
+        // Result rows and the window title (a bare `Path::file_name`) can be any language,
+        // so this has to cover the whole Basic Multilingual Plane.
+        //
+        // `imgui::FontAtlas::add_font` merges every entry after the first *into* that first
+        // entry (Lucon, the default font) rather than creating separate selectable fonts, so
+        // putting this in the same `add_font` call as Lucon/mplus is what makes it a true
+        // fallback for the default font, not just for mplus.
+        //
+        // No oversampling here: at 4x4 over the full 0x0020..=0xFFFF range the atlas can
+        // exceed GL_MAX_TEXTURE_SIZE, and this face is rebuilt on every DPI change and zoom
+        // step, not just once at startup.
+        FontSource::TtfData {
+            data: include_bytes!("../resources/unifont-regular.otf"),
+            size_pixels: base_font_sizes[1] * hidpi_factor,
+            config: Some(FontConfig {
+                oversample_h: 1,
+                oversample_v: 1,
+                glyph_ranges: FontGlyphRanges::from_slice(&[0x0020, 0xFFFF, 0]),
+                ..FontConfig::default()
+            }),
+        },
+    ]);
+}
+
+// Clears and re-rasterizes the font atlas at `(base_font_sizes + font_size_offset) *
+// hidpi_factor`, resets the style from the unscaled `base_style` and uploads the new atlas
+// texture. Used both when the window moves to a monitor with a different scale factor and
+// when the user zooms the UI font at runtime.
+//
+// Returns whether the new atlas was uploaded successfully. `fonts().clear()` below always
+// replaces the atlas *metadata* (glyph UVs etc.) up front, so a failed upload leaves it
+// describing a texture that was never actually bound; callers must rebuild again at a
+// previously-working size on failure rather than leaving that mismatch in place.
+fn rebuild_fonts(
+    imgui: &mut Context,
+    renderer: &mut Renderer,
+    base_font_sizes: [f32; 2],
+    font_size_offset: f32,
+    base_style: &imgui::Style,
+    hidpi_factor: f32,
+) -> bool {
+    imgui.fonts().clear();
+    let sizes = [
+        base_font_sizes[0] + font_size_offset,
+        base_font_sizes[1] + font_size_offset,
+    ];
+    build_fonts(imgui, sizes, hidpi_factor);
+    *imgui.style_mut() = base_style.clone();
+    imgui.style_mut().scale_all_sizes(hidpi_factor);
+    // An oversized atlas (e.g. the full-BMP fallback face at a large zoom/DPI) can fail here
+    // if it exceeds GL_MAX_TEXTURE_SIZE; this runs on every DPI change and zoom step.
+    match renderer.reload_font_texture(imgui) {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("Failed to reload font texture: {}", e);
+            false
+        }
+    }
+}
+
+// Snapshots the current window geometry (minus the configured padding, so it round-trips)
+// and writes it out alongside the DPI override, mirroring how terminal emulators persist
+// window padding/scale in their own config rather than recomputing defaults every launch.
+fn persist_window_config(display: &Display, padding: (f32, f32), dpi_override: Option<f64>) {
+    let gl_window = display.gl_window();
+    let window = gl_window.window();
+    let scale_factor = window.scale_factor();
+    let size = window.inner_size().to_logical::<f64>(scale_factor);
+    let position = window
+        .outer_position()
+        .ok()
+        .map(|p| p.to_logical::<f64>(scale_factor))
+        .map(|p: glutin::dpi::LogicalPosition<f64>| (p.x, p.y));
+
+    config::save(&config::WindowConfig {
+        width: (size.width - padding.0 as f64).max(1.0),
+        height: (size.height - padding.1 as f64).max(1.0),
+        position,
+        dpi_override,
+        padding,
+    });
+}
+
+// A saved position can predate a monitor layout change (unplugged display, different
+// arrangement) and land fully off-screen. Only honor it if the window would actually be
+// reachable on some currently-connected monitor; otherwise let the OS pick a default spot.
+fn resolve_window_position(
+    event_loop: &EventLoop<()>,
+    position: (f64, f64),
+    logical_size: (f64, f64),
+) -> Option<glutin::dpi::LogicalPosition<f64>> {
+    let (x, y) = position;
+    let (width, height) = logical_size;
+
+    let reachable = event_loop.available_monitors().any(|monitor| {
+        let scale = monitor.scale_factor();
+        let monitor_pos = monitor.position().to_logical::<f64>(scale);
+        let monitor_size = monitor.size().to_logical::<f64>(scale);
+        x < monitor_pos.x + monitor_size.width
+            && x + width > monitor_pos.x
+            && y < monitor_pos.y + monitor_size.height
+            && y + height > monitor_pos.y
+    });
+
+    if reachable {
+        Some(glutin::dpi::LogicalPosition::new(x, y))
+    } else {
+        None
+    }
 }
 
 fn load_icon() -> Option<Icon> {
@@ -35,12 +200,28 @@ pub fn init(title: &str) -> System {
         Some(file_name) => file_name.to_str().unwrap(),
         None => title,
     };
+    let window_config = config::load();
+
     let event_loop = EventLoop::new();
     let context = glutin::ContextBuilder::new().with_vsync(true);
-    let builder = WindowBuilder::new()
+    let logical_size = (
+        window_config.width + window_config.padding.0 as f64,
+        window_config.height + window_config.padding.1 as f64,
+    );
+    let mut builder = WindowBuilder::new()
         .with_title(title.to_owned())
-        .with_inner_size(glutin::dpi::LogicalSize::new(1024f64, 768f64))
+        .with_inner_size(glutin::dpi::LogicalSize::new(
+            logical_size.0,
+            logical_size.1,
+        ))
         .with_window_icon(load_icon());
+    if let Some(position) = window_config.position {
+        if let Some(logical_position) =
+            resolve_window_position(&event_loop, position, logical_size)
+        {
+            builder = builder.with_position(logical_position);
+        }
+    }
     let display =
         Display::new(builder, context, &event_loop).expect("Failed to initialize display");
 
@@ -54,18 +235,30 @@ pub fn init(title: &str) -> System {
     }
 
     let mut platform = WinitPlatform::init(&mut imgui);
+    // This is the value we persist; it must never be fed by `IMGUI_EXAMPLE_FORCE_DPI_FACTOR`,
+    // or one debug launch with the env var set would permanently pin that factor in the
+    // config file for every later run that doesn't set it.
+    let dpi_override = window_config.dpi_override;
+    let mut dpi_locked = dpi_override.is_some();
     {
         let gl_window = display.gl_window();
         let window = gl_window.window();
 
         let dpi_mode = if let Ok(factor) = std::env::var("IMGUI_EXAMPLE_FORCE_DPI_FACTOR") {
-            // Allow forcing of HiDPI factor for debugging purposes
+            // Allow forcing of HiDPI factor for debugging purposes; this overrides the
+            // config value for the current run only and is never written back out.
             match factor.parse::<f64>() {
-                Ok(f) => HiDpiMode::Locked(f),
+                Ok(f) => {
+                    dpi_locked = true;
+                    HiDpiMode::Locked(f)
+                }
                 Err(e) => panic!("Invalid scaling factor: {}", e),
             }
         } else {
-            HiDpiMode::Default
+            match dpi_override {
+                Some(f) => HiDpiMode::Locked(f),
+                None => HiDpiMode::Default,
+            }
         };
 
         platform.attach_window(imgui.io_mut(), window, dpi_mode);
@@ -73,36 +266,8 @@ pub fn init(title: &str) -> System {
 
     let hidpi_factor = platform.hidpi_factor() as f32 ;
 
-    imgui.fonts().add_font(&[
-        FontSource::TtfData {
-            data: include_bytes!("../resources/Lucon.ttf"),
-            size_pixels: 12.0 * hidpi_factor,
-            config: Some(FontConfig {
-                // As imgui-glium-renderer isn't gamma-correct with it's font rendering,
-                // we apply an arbitrary multiplier to make the font a bit "heavier".
-                // With default imgui-glow-renderer this is unnecessary.
-                rasterizer_multiply: 1.2,
-                // Oversampling font helps improve text rendering at expense of larger
-                // font atlas texture.
-                oversample_h: 4,
-                oversample_v: 4,
-                ..FontConfig::default()
-            }),
-        },
-        FontSource::TtfData {
-            data: include_bytes!("../resources/mplus-1p-regular.ttf"),
-            size_pixels: 15.0 * hidpi_factor,
-            config: Some(FontConfig {
-                // Oversampling font helps improve text rendering at expense of larger
-                // font atlas texture.
-                oversample_h: 4,
-                oversample_v: 4,
-                // Range of glyphs to rasterize
-                glyph_ranges: FontGlyphRanges::japanese(),
-                ..FontConfig::default()
-            }),
-        },
-    ]);
+    let base_font_sizes = [12.0f32, 15.0f32];
+    build_fonts(&mut imgui, base_font_sizes, hidpi_factor);
 
     // @Cleanup:
     // This is apprently necessary on MacOS, because it pretend it has 2x less pixel
@@ -113,6 +278,7 @@ pub fn init(title: &str) -> System {
     //
     // imgui.io_mut().font_global_scale = (1.0 / hidpi_factor) as f32;
 
+    let base_style = imgui.style().clone();
     imgui.style_mut().scale_all_sizes(hidpi_factor);
 
     let renderer = Renderer::init(&mut imgui, &display).expect("Failed to initialize renderer");
@@ -123,6 +289,13 @@ pub fn init(title: &str) -> System {
         imgui,
         platform,
         renderer,
+        base_font_sizes,
+        base_style,
+        hidpi_factor,
+        font_size_offset: 0.0,
+        window_padding: window_config.padding,
+        dpi_override,
+        dpi_locked,
     };
 }
 
@@ -134,7 +307,13 @@ impl System {
             mut imgui,
             mut platform,
             mut renderer,
-            ..
+            base_font_sizes,
+            base_style,
+            mut hidpi_factor,
+            mut font_size_offset,
+            window_padding,
+            dpi_override,
+            dpi_locked,
         } = self;
 
         // Allow us to use PageUp and PageDown to navigate in the result window.
@@ -144,6 +323,17 @@ impl System {
             .set(ConfigFlags::NAV_ENABLE_KEYBOARD, true);
 
         let mut last_frame = Instant::now();
+        let mut ctrl_down = false;
+        // Set when a zoom chord/scroll changes `font_size_offset`; the atlas rebuild
+        // (expensive) is deferred to `MainEventsCleared` so several events in one frame
+        // only pay for one `reload_font_texture`.
+        let mut font_size_dirty = false;
+        // Last `(font_size_offset, hidpi_factor)` pair that successfully uploaded an atlas
+        // texture. `rebuild_fonts` can fail (atlas too large for GL_MAX_TEXTURE_SIZE) after
+        // already clearing the old atlas metadata, so on failure we fall back to re-rendering
+        // at this pair to bring the atlas and the bound GL texture back in sync.
+        let mut committed_offset = font_size_offset;
+        let mut committed_hidpi = hidpi_factor;
         event_loop.run(move |event, _, control_flow| match event {
             Event::NewEvents(_) => {
                 let now = Instant::now();
@@ -151,6 +341,32 @@ impl System {
                 last_frame = now;
             }
             Event::MainEventsCleared => {
+                if font_size_dirty {
+                    if rebuild_fonts(
+                        &mut imgui,
+                        &mut renderer,
+                        base_font_sizes,
+                        font_size_offset,
+                        &base_style,
+                        hidpi_factor,
+                    ) {
+                        committed_offset = font_size_offset;
+                        committed_hidpi = hidpi_factor;
+                    } else {
+                        font_size_offset = committed_offset;
+                        hidpi_factor = committed_hidpi;
+                        rebuild_fonts(
+                            &mut imgui,
+                            &mut renderer,
+                            base_font_sizes,
+                            font_size_offset,
+                            &base_style,
+                            hidpi_factor,
+                        );
+                    }
+                    font_size_dirty = false;
+                }
+
                 let gl_window = display.gl_window();
                 platform
                     .prepare_frame(imgui.io_mut(), gl_window.window())
@@ -163,6 +379,7 @@ impl System {
                 let mut run = true;
                 app.update(&mut run, ui);
                 if !run {
+                    persist_window_config(&display, window_padding, dpi_override);
                     *control_flow = ControlFlow::Exit;
                 }
 
@@ -181,7 +398,10 @@ impl System {
             Event::WindowEvent {
                 event: WindowEvent::CloseRequested,
                 ..
-            } => *control_flow = ControlFlow::Exit,
+            } => {
+                persist_window_config(&display, window_padding, dpi_override);
+                *control_flow = ControlFlow::Exit;
+            }
             Event::WindowEvent {
                 event: WindowEvent::Resized(new_size),
                 ..
@@ -199,10 +419,99 @@ impl System {
             } => imgui.io_mut().add_mouse_pos_event([position.x as f32, position.y as f32]),
             */
             event => {
+                // While `HiDpiMode::Locked` is active, `platform` ignores OS scale-factor
+                // changes and keeps reporting the pinned factor; rebuilding the atlas at the
+                // raw event value would desync text/style scaling from the mouse coordinates
+                // and widget geometry `platform` keeps locked. So only rebuild when unlocked.
+                let scale_factor_changed = !dpi_locked
+                    && matches!(
+                        &event,
+                        Event::WindowEvent {
+                            event: WindowEvent::ScaleFactorChanged { .. },
+                            ..
+                        }
+                    );
+
+                if let Event::WindowEvent {
+                    event: WindowEvent::ModifiersChanged(modifiers),
+                    ..
+                } = &event
+                {
+                    ctrl_down = modifiers.ctrl();
+                }
+
+                if let Event::WindowEvent {
+                    event: WindowEvent::KeyboardInput { input, .. },
+                    ..
+                } = &event
+                {
+                    if ctrl_down && input.state == ElementState::Pressed {
+                        let new_offset = match input.virtual_keycode {
+                            Some(VirtualKeyCode::Equals) => {
+                                Some(font_size_offset + 1.0)
+                            }
+                            Some(VirtualKeyCode::Minus) => Some(font_size_offset - 1.0),
+                            Some(VirtualKeyCode::Key0) => Some(0.0),
+                            _ => None,
+                        };
+                        if let Some(offset) = new_offset {
+                            font_size_offset =
+                                offset.clamp(FONT_SIZE_OFFSET_MIN, FONT_SIZE_OFFSET_MAX);
+                            font_size_dirty = true;
+                        }
+                    }
+                }
+
+                if let Event::WindowEvent {
+                    event: WindowEvent::MouseWheel { delta, .. },
+                    ..
+                } = &event
+                {
+                    if ctrl_down {
+                        let steps = match delta {
+                            MouseScrollDelta::LineDelta(_, y) => *y,
+                            MouseScrollDelta::PixelDelta(pos) => (pos.y / 20.0) as f32,
+                        };
+                        if steps != 0.0 {
+                            font_size_offset = (font_size_offset + steps.signum())
+                                .clamp(FONT_SIZE_OFFSET_MIN, FONT_SIZE_OFFSET_MAX);
+                            font_size_dirty = true;
+                        }
+                    }
+                }
+
                 let gl_window = display.gl_window();
                 if !app.handle_event(gl_window.window(), &event) {
                     platform.handle_event(imgui.io_mut(), gl_window.window(), &event);
                 }
+
+                if scale_factor_changed {
+                    // Read back the factor `platform` actually adopted (rather than the raw
+                    // event value) now that it has observed the event above.
+                    hidpi_factor = platform.hidpi_factor() as f32;
+                    if rebuild_fonts(
+                        &mut imgui,
+                        &mut renderer,
+                        base_font_sizes,
+                        font_size_offset,
+                        &base_style,
+                        hidpi_factor,
+                    ) {
+                        committed_offset = font_size_offset;
+                        committed_hidpi = hidpi_factor;
+                    } else {
+                        font_size_offset = committed_offset;
+                        hidpi_factor = committed_hidpi;
+                        rebuild_fonts(
+                            &mut imgui,
+                            &mut renderer,
+                            base_font_sizes,
+                            font_size_offset,
+                            &base_style,
+                            hidpi_factor,
+                        );
+                    }
+                }
             }
         });
     }